@@ -129,11 +129,19 @@ fn create_object_file(sess: &Session) -> Option<write::Object<'static>> {
     } else {
         BinaryFormat::Elf
     };
+    // LoongArch objects can only be written as ELF.
+    if architecture == Architecture::LoongArch64 && binary_format != BinaryFormat::Elf {
+        return None;
+    }
 
     let mut file = write::Object::new(binary_format, architecture, endianness);
     match architecture {
         Architecture::LoongArch64 => {
-            let e_flags = elf::EF_LARCH_ABI_LP64D;
+            let e_flags = match &*sess.target.options.llvm_abiname {
+                "lp64s" => elf::EF_LARCH_ABI_LP64S,
+                "lp64f" => elf::EF_LARCH_ABI_LP64F,
+                _ => elf::EF_LARCH_ABI_LP64D,
+            };
             file.flags = FileFlags::Elf { e_flags };
         }
         Architecture::Mips => {
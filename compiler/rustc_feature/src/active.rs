@@ -252,6 +252,7 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, ermsb_target_feature, "1.49.0", Some(44839), None),
     (active, f16c_target_feature, "1.36.0", Some(44839), None),
     (active, hexagon_target_feature, "1.27.0", Some(44839), None),
+    (active, loongarch_target_feature, "1.61.0", Some(44839), None),
     (active, mips_target_feature, "1.27.0", Some(44839), None),
     (active, movbe_target_feature, "1.34.0", Some(44839), None),
     (active, powerpc_target_feature, "1.27.0", Some(44839), None),
@@ -1314,6 +1314,24 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
         }
     }
 
+    if let Some(code_model) = sess.opts.cg.code_model {
+        if !sess.target.is_code_model_supported(code_model) {
+            sess.err(&format!(
+                "`-C code-model={}` is not supported for target {}",
+                code_model.desc(),
+                sess.opts.target_triple
+            ));
+        }
+    } else if let Some(code_model) = sess.target.code_model {
+        if !sess.target.is_code_model_supported(code_model) {
+            sess.err(&format!(
+                "the default code model `{}` is not supported for target {}",
+                code_model.desc(),
+                sess.opts.target_triple
+            ));
+        }
+    }
+
     // Sanitizers can only be used on platforms that we know have working sanitizer codegen.
     let supported_sanitizers = sess.target.options.supported_sanitizers;
     let unsupported_sanitizers = sess.opts.debugging_opts.sanitizer - supported_sanitizers;
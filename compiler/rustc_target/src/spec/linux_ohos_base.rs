@@ -0,0 +1,19 @@
+use crate::spec::TargetOptions;
+
+// OpenHarmony uses a musl-derived libc, but neither `libc` nor `unwind` know about
+// `target_env = "ohos"` yet, so only `core` and `alloc` are supported for now.
+pub fn opts() -> TargetOptions {
+    let mut base = super::linux_musl_base::opts();
+
+    base.env = "ohos".into();
+    // The OpenHarmony SDK ships its own crt objects and a dynamically linked libc, so don't fall
+    // back to the self-contained musl ones.
+    base.pre_link_objects_fallback.clear();
+    base.post_link_objects_fallback.clear();
+    base.crt_objects_fallback = None;
+    base.crt_static_default = false;
+    // Don't expose `cfg(target_thread_local)`, so std keeps its thread locals in pthread keys.
+    base.has_thread_local = false;
+
+    base
+}
@@ -0,0 +1,5 @@
+use crate::spec::Target;
+
+pub fn target() -> Target {
+    super::loongarch_base::ilp32d("loongarch32-unknown-linux-gnu", super::linux_gnu_base::opts())
+}
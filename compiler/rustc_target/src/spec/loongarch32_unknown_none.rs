@@ -0,0 +1,23 @@
+//! Bare LoongArch32, softfloat, ILP32S calling convention
+//!
+//! Intended for microcontroller-class LoongArch32 parts without an FPU.
+
+use crate::spec::{CodeModel, LinkerFlavor, LldFlavor, PanicStrategy, RelocModel};
+use crate::spec::{Target, TargetOptions};
+
+pub fn target() -> Target {
+    super::loongarch_base::ilp32s(
+        "loongarch32-unknown-none",
+        TargetOptions {
+            linker_flavor: LinkerFlavor::Lld(LldFlavor::Ld),
+            linker: Some("rust-lld".into()),
+            executables: true,
+            panic_strategy: PanicStrategy::Abort,
+            relocation_model: RelocModel::Static,
+            code_model: Some(CodeModel::Small),
+            emit_debug_gdb_scripts: false,
+            eh_frame_header: false,
+            ..Default::default()
+        },
+    )
+}
@@ -0,0 +1,5 @@
+use crate::spec::Target;
+
+pub fn target() -> Target {
+    super::loongarch_base::lp64d("loongarch64-unknown-freebsd", super::freebsd_base::opts())
+}
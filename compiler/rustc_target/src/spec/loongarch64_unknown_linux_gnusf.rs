@@ -0,0 +1,8 @@
+use crate::spec::{Target, TargetOptions};
+
+pub fn target() -> Target {
+    super::loongarch_base::lp64s(
+        "loongarch64-unknown-linux-gnusf",
+        TargetOptions { abi: "softfloat".into(), ..super::linux_gnu_base::opts() },
+    )
+}
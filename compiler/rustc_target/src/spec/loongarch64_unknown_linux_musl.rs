@@ -0,0 +1,5 @@
+use crate::spec::Target;
+
+pub fn target() -> Target {
+    super::loongarch_base::lp64d("loongarch64-unknown-linux-musl", super::linux_musl_base::opts())
+}
@@ -0,0 +1,8 @@
+use crate::spec::{Target, TargetOptions};
+
+pub fn target() -> Target {
+    super::loongarch_base::lp64d(
+        "loongarch64-unknown-netbsd",
+        TargetOptions { mcount: "__mcount".into(), ..super::netbsd_base::opts() },
+    )
+}
@@ -0,0 +1,71 @@
+// Options shared by all LoongArch targets. Each target composes one of the helpers below with its
+// OS base options, so that the baseline CPU, the floating-point ABI and the target features that
+// ABI requires are only spelled out once. The ABI names follow the LoongArch ELF psABI: the suffix
+// `d` means both single- and double-precision values are passed in FPRs, `s` means the FPRs are
+// not used at all.
+//
+// The helpers always set `cpu`, `features`, `llvm_abiname` and `max_atomic_width`, overriding
+// whatever the passed-in options contain, since those must agree with the chosen ABI (see the
+// consistency check in `tests_impl.rs`). `code_model` is only a default and is kept if the caller
+// already set one. All other options are taken from the passed-in ones.
+
+use crate::spec::{CodeModel, Target, TargetOptions};
+
+/// LoongArch64 using the LP64D ABI.
+pub fn lp64d(llvm_target: &'static str, opts: TargetOptions) -> Target {
+    la64(
+        llvm_target,
+        TargetOptions { features: "+f,+d".into(), llvm_abiname: "lp64d".into(), ..opts },
+    )
+}
+
+/// LoongArch64 using the LP64S soft-float ABI.
+pub fn lp64s(llvm_target: &'static str, opts: TargetOptions) -> Target {
+    la64(
+        llvm_target,
+        TargetOptions { features: "-f,-d".into(), llvm_abiname: "lp64s".into(), ..opts },
+    )
+}
+
+/// LoongArch32 using the ILP32D ABI.
+pub fn ilp32d(llvm_target: &'static str, opts: TargetOptions) -> Target {
+    la32(
+        llvm_target,
+        TargetOptions { features: "+f,+d".into(), llvm_abiname: "ilp32d".into(), ..opts },
+    )
+}
+
+/// LoongArch32 using the ILP32S soft-float ABI.
+pub fn ilp32s(llvm_target: &'static str, opts: TargetOptions) -> Target {
+    la32(
+        llvm_target,
+        TargetOptions { features: "-f,-d".into(), llvm_abiname: "ilp32s".into(), ..opts },
+    )
+}
+
+fn la64(llvm_target: &'static str, opts: TargetOptions) -> Target {
+    Target {
+        llvm_target: llvm_target.into(),
+        pointer_width: 64,
+        data_layout: "e-m:e-p:64:64-i8:8:32-i16:16:32-i64:64-n32:64-S128".into(),
+        arch: "loongarch64".into(),
+        options: TargetOptions {
+            cpu: "la464".into(),
+            max_atomic_width: Some(64),
+            // Direct calls in the normal code model only reach +/-128MiB, which large statically
+            // linked binaries exceed. The medium code model extends that to +/-128GiB.
+            code_model: opts.code_model.or(Some(CodeModel::Medium)),
+            ..opts
+        },
+    }
+}
+
+fn la32(llvm_target: &'static str, opts: TargetOptions) -> Target {
+    Target {
+        llvm_target: llvm_target.into(),
+        pointer_width: 32,
+        data_layout: "e-m:e-p:32:32-i64:64-n32-S128".into(),
+        arch: "loongarch32".into(),
+        options: TargetOptions { cpu: "generic-la32".into(), max_atomic_width: Some(32), ..opts },
+    }
+}
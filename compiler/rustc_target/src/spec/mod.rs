@@ -70,7 +70,9 @@
 mod linux_gnu_base;
 mod linux_kernel_base;
 mod linux_musl_base;
+mod linux_ohos_base;
 mod linux_uclibc_base;
+mod loongarch_base;
 mod msvc_base;
 mod netbsd_base;
 mod openbsd_base;
@@ -358,8 +360,8 @@ fn from_str(s: &str) -> Result<CodeModel, ()> {
     }
 }
 
-impl ToJson for CodeModel {
-    fn to_json(&self) -> Json {
+impl CodeModel {
+    pub fn desc(&self) -> &'static str {
         match *self {
             CodeModel::Tiny => "tiny",
             CodeModel::Small => "small",
@@ -367,7 +369,12 @@ fn to_json(&self) -> Json {
             CodeModel::Medium => "medium",
             CodeModel::Large => "large",
         }
-        .to_json()
+    }
+}
+
+impl ToJson for CodeModel {
+    fn to_json(&self) -> Json {
+        self.desc().to_json()
     }
 }
 
@@ -866,6 +873,7 @@ fn $module() {
     ("armv7-linux-androideabi", armv7_linux_androideabi),
     ("thumbv7neon-linux-androideabi", thumbv7neon_linux_androideabi),
     ("aarch64-linux-android", aarch64_linux_android),
+    ("loongarch64-linux-android", loongarch64_linux_android),
 
     ("x86_64-unknown-none-linuxkernel", x86_64_unknown_none_linuxkernel),
 
@@ -873,6 +881,7 @@ fn $module() {
     ("armv6-unknown-freebsd", armv6_unknown_freebsd),
     ("armv7-unknown-freebsd", armv7_unknown_freebsd),
     ("i686-unknown-freebsd", i686_unknown_freebsd),
+    ("loongarch64-unknown-freebsd", loongarch64_unknown_freebsd),
     ("powerpc-unknown-freebsd", powerpc_unknown_freebsd),
     ("powerpc64-unknown-freebsd", powerpc64_unknown_freebsd),
     ("powerpc64le-unknown-freebsd", powerpc64le_unknown_freebsd),
@@ -883,6 +892,7 @@ fn $module() {
 
     ("aarch64-unknown-openbsd", aarch64_unknown_openbsd),
     ("i686-unknown-openbsd", i686_unknown_openbsd),
+    ("loongarch64-unknown-openbsd", loongarch64_unknown_openbsd),
     ("sparc64-unknown-openbsd", sparc64_unknown_openbsd),
     ("x86_64-unknown-openbsd", x86_64_unknown_openbsd),
     ("powerpc-unknown-openbsd", powerpc_unknown_openbsd),
@@ -891,6 +901,7 @@ fn $module() {
     ("armv6-unknown-netbsd-eabihf", armv6_unknown_netbsd_eabihf),
     ("armv7-unknown-netbsd-eabihf", armv7_unknown_netbsd_eabihf),
     ("i686-unknown-netbsd", i686_unknown_netbsd),
+    ("loongarch64-unknown-netbsd", loongarch64_unknown_netbsd),
     ("powerpc-unknown-netbsd", powerpc_unknown_netbsd),
     ("sparc64-unknown-netbsd", sparc64_unknown_netbsd),
     ("x86_64-unknown-netbsd", x86_64_unknown_netbsd),
@@ -904,6 +915,7 @@ fn $module() {
 
     ("aarch64-fuchsia", aarch64_fuchsia),
     ("x86_64-fuchsia", x86_64_fuchsia),
+    ("loongarch64-unknown-fuchsia", loongarch64_unknown_fuchsia),
 
     ("avr-unknown-gnu-atmega328", avr_unknown_gnu_atmega328),
 
@@ -911,6 +923,7 @@ fn $module() {
 
     ("aarch64-unknown-redox", aarch64_unknown_redox),
     ("x86_64-unknown-redox", x86_64_unknown_redox),
+    ("loongarch64-unknown-redox", loongarch64_unknown_redox),
 
     ("i386-apple-ios", i386_apple_ios),
     ("x86_64-apple-ios", x86_64_apple_ios),
@@ -970,6 +983,7 @@ fn $module() {
 
     ("aarch64-unknown-hermit", aarch64_unknown_hermit),
     ("x86_64-unknown-hermit", x86_64_unknown_hermit),
+    ("loongarch64-unknown-hermit", loongarch64_unknown_hermit),
 
     ("riscv32i-unknown-none-elf", riscv32i_unknown_none_elf),
     ("riscv32im-unknown-none-elf", riscv32im_unknown_none_elf),
@@ -998,6 +1012,7 @@ fn $module() {
     ("x86_64-wrs-vxworks", x86_64_wrs_vxworks),
     ("armv7-wrs-vxworks-eabihf", armv7_wrs_vxworks_eabihf),
     ("aarch64-wrs-vxworks", aarch64_wrs_vxworks),
+    ("loongarch64-wrs-vxworks", loongarch64_wrs_vxworks),
     ("powerpc-wrs-vxworks", powerpc_wrs_vxworks),
     ("powerpc-wrs-vxworks-spe", powerpc_wrs_vxworks_spe),
     ("powerpc64-wrs-vxworks", powerpc64_wrs_vxworks),
@@ -1026,7 +1041,14 @@ fn $module() {
 
     ("mips64-openwrt-linux-musl", mips64_openwrt_linux_musl),
 
+    ("loongarch32-unknown-linux-gnu", loongarch32_unknown_linux_gnu),
+    ("loongarch32-unknown-none", loongarch32_unknown_none),
     ("loongarch64-unknown-linux-gnu", loongarch64_unknown_linux_gnu),
+    ("loongarch64-unknown-linux-gnusf", loongarch64_unknown_linux_gnusf),
+    ("loongarch64-unknown-linux-musl", loongarch64_unknown_linux_musl),
+    ("loongarch64-unknown-linux-ohos", loongarch64_unknown_linux_ohos),
+    ("loongarch64-unknown-none", loongarch64_unknown_none),
+    ("loongarch64-unknown-none-softfloat", loongarch64_unknown_none_softfloat),
 }
 
 /// Cow-Vec-Str: Cow<'static, [Cow<'static, str>]>
@@ -1664,6 +1686,19 @@ pub fn is_abi_supported(&self, abi: Abi) -> Option<bool> {
         })
     }
 
+    /// Returns whether the given code model can be used on this target.
+    pub fn is_code_model_supported(&self, code_model: CodeModel) -> bool {
+        match &self.arch[..] {
+            // LoongArch's normal, medium and extreme code models are LLVM's small, medium and
+            // large ones. Only the normal code model is defined for LA32.
+            "loongarch32" => code_model == CodeModel::Small,
+            "loongarch64" => {
+                matches!(code_model, CodeModel::Small | CodeModel::Medium | CodeModel::Large)
+            }
+            _ => true,
+        }
+    }
+
     /// Minimum integer size in bits that this target can perform atomic
     /// operations on.
     pub fn min_atomic_width(&self) -> u64 {
@@ -44,6 +44,25 @@ fn check_consistency(&self) {
             // Keep the default "none" for bare metal targets instead.
             assert_ne!(self.os, "unknown");
         }
+        if self.arch == "loongarch32" || self.arch == "loongarch64" {
+            // The ABI name must agree with the register width and with the enabled
+            // floating-point extensions, see `loongarch_base`.
+            let (xlen, flen) = match &self.llvm_abiname[..] {
+                "ilp32s" => (32, 0),
+                "ilp32f" => (32, 32),
+                "ilp32d" => (32, 64),
+                "lp64s" => (64, 0),
+                "lp64f" => (64, 32),
+                "lp64d" => (64, 64),
+                abi => panic!("unknown LoongArch ABI `{}`", abi),
+            };
+            assert_eq!(self.pointer_width, xlen);
+            assert_eq!(self.arch, if xlen == 32 { "loongarch32" } else { "loongarch64" });
+            assert_eq!(self.max_atomic_width, Some(xlen as u64));
+            let features: Vec<_> = self.features.split(',').collect();
+            assert_eq!(features.contains(&"+f"), flen >= 32);
+            assert_eq!(features.contains(&"+d"), flen == 64);
+        }
     }
 
     // Add your target to the whitelist if it has `std` library
@@ -126,7 +126,7 @@ fn rust_exception_class() -> uw::_Unwind_Exception_Class {
 #[cfg(any(target_arch = "riscv64", target_arch = "riscv32"))]
 const UNWIND_DATA_REG: (i32, i32) = (10, 11); // x10, x11
 
-#[cfg(target_arch = "loongarch64")]
+#[cfg(any(target_arch = "loongarch32", target_arch = "loongarch64"))]
 const UNWIND_DATA_REG: (i32, i32) = (4, 5); // R4, R5 / A0, A1
 
 // The following code is based on GCC's C and C++ personality routines.  For reference, see:
@@ -237,6 +237,7 @@ pub struct stat {
     target_arch = "sparc64",
     target_arch = "riscv64",
     target_arch = "riscv32",
+    target_arch = "loongarch32",
     target_arch = "loongarch64"
 ))]
 mod arch {
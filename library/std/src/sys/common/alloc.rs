@@ -14,6 +14,7 @@
     target_arch = "asmjs",
     target_arch = "wasm32",
     target_arch = "hexagon",
+    target_arch = "loongarch32",
     all(target_arch = "riscv32", not(target_os = "espidf")),
     all(target_arch = "xtensa", not(target_os = "espidf")),
 )))]
@@ -0,0 +1,26 @@
+// Checks the code model that LoongArch targets pick by default and that an explicit
+// `-C code-model` overrides it.
+
+// revisions: LA64-DEFAULT LA64-LARGE LA32-NONE
+//[LA64-DEFAULT] compile-flags: --target loongarch64-unknown-linux-gnu
+//[LA64-DEFAULT] needs-llvm-components: loongarch
+//[LA64-LARGE] compile-flags: --target loongarch64-unknown-linux-gnu -C code-model=large
+//[LA64-LARGE] needs-llvm-components: loongarch
+//[LA32-NONE] compile-flags: --target loongarch32-unknown-none
+//[LA32-NONE] needs-llvm-components: loongarch
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+pub fn f() {}
+
+// LA64-DEFAULT: !llvm.module.flags = !{{{.*}}}
+// LA64-DEFAULT: !{{[0-9]+}} = !{i32 1, !"Code Model", i32 3}
+// LA64-LARGE: !llvm.module.flags = !{{{.*}}}
+// LA64-LARGE: !{{[0-9]+}} = !{i32 1, !"Code Model", i32 4}
+// LA32-NONE: !llvm.module.flags = !{{{.*}}}
+// LA32-NONE: !{{[0-9]+}} = !{i32 1, !"Code Model", i32 1}
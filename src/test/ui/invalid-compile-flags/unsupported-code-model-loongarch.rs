@@ -0,0 +1,13 @@
+// revisions: LA32 LA64
+// [LA32] compile-flags: --target=loongarch32-unknown-none -Ccode-model=medium
+// [LA32] needs-llvm-components: loongarch
+// [LA64] compile-flags: --target=loongarch64-unknown-linux-gnu -Ccode-model=kernel
+// [LA64] needs-llvm-components: loongarch
+// check-fail
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang="sized"]
+trait Sized { }
@@ -1,8 +1,14 @@
-// revisions: aarch64-neon aarch64-sve2
+// revisions: aarch64-neon aarch64-sve2 loongarch64-lsx loongarch64-lasx
 // [aarch64-neon] compile-flags: -Ctarget-feature=+neon --target=aarch64-unknown-linux-gnu
 // [aarch64-neon] needs-llvm-components: aarch64
 // [aarch64-sve2] compile-flags: -Ctarget-feature=-neon,+sve2 --target=aarch64-unknown-linux-gnu
 // [aarch64-sve2] needs-llvm-components: aarch64
+// [loongarch64-lsx] compile-flags: -Ctarget-feature=+lsx --target=loongarch64-unknown-linux-gnu
+// [loongarch64-lsx] compile-flags: -Ctarget-cpu=generic-la64
+// [loongarch64-lsx] needs-llvm-components: loongarch
+// [loongarch64-lasx] compile-flags: -Ctarget-feature=+lasx --target=loongarch64-unknown-linux-gnu
+// [loongarch64-lasx] compile-flags: -Ctarget-cpu=generic-la64
+// [loongarch64-lasx] needs-llvm-components: loongarch
 // build-pass
 #![no_core]
 #![crate_type = "rlib"]
@@ -56,3 +62,18 @@ fn check_sve2_includes_neon() {
     assert!(cfg!(target_feature = "neon"));
     assert!(cfg!(target_feature = "sve2"));
 }
+
+#[cfg(loongarch64_lsx)]
+fn check_lsx_not_lasx() {
+    // This checks that enabling lsx doesn't also enable lasx. The revision uses the generic CPU,
+    // because la464 already enables lasx by default.
+    assert!(cfg!(target_feature = "lsx"));
+    assert!(cfg!(not(target_feature = "lasx")));
+}
+
+#[cfg(loongarch64_lasx)]
+fn check_lasx_includes_lsx() {
+    // This checks that loongarch64's lasx includes lsx
+    assert!(cfg!(target_feature = "lsx"));
+    assert!(cfg!(target_feature = "lasx"));
+}
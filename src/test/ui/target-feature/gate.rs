@@ -8,6 +8,7 @@
 // ignore-powerpc64
 // ignore-powerpc64le
 // ignore-riscv64
+// ignore-loongarch64
 // ignore-sparc
 // ignore-sparc64
 // ignore-s390x
@@ -25,6 +26,7 @@
 // gate-test-rtm_target_feature
 // gate-test-f16c_target_feature
 // gate-test-riscv_target_feature
+// gate-test-loongarch_target_feature
 // gate-test-ermsb_target_feature
 // gate-test-bpf_target_feature
 // gate-test-aarch64_ver_target_feature
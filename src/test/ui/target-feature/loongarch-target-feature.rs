@@ -0,0 +1,15 @@
+// build-pass
+// compile-flags: --crate-type=rlib --target=loongarch64-unknown-linux-gnu
+// needs-llvm-components: loongarch
+#![feature(no_core, lang_items, loongarch_target_feature)]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+// Checks that the LoongArch vector extensions can be enabled per function.
+#[target_feature(enable = "lsx")]
+pub unsafe fn with_lsx() {}
+
+#[target_feature(enable = "lasx")]
+pub unsafe fn with_lasx() {}
@@ -54,6 +54,8 @@
     ("i386", "x86"),
     ("i586", "x86"),
     ("i686", "x86"),
+    ("loongarch32", "loongarch32"),
+    ("loongarch64", "loongarch64"),
     ("m68k", "m68k"),
     ("mips", "mips"),
     ("mips64", "mips64"),